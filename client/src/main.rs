@@ -1,6 +1,8 @@
 use ggez::{conf, event, graphics, ContextBuilder, Context, GameError, GameResult};
-use std::{path, env, collections::HashMap};
-use osveijer_chess::{Game, Colour, Piece};
+use ggez::graphics::spritebatch::SpriteBatch;
+use std::{path, env};
+use osveijer_chess::{Game, Colour, Piece, GameState};
+use serde::{Serialize, Deserialize};
 
 /// A chess board is 8x8 tiles.
 const GRID_SIZE: i16 = 8;
@@ -18,51 +20,436 @@ const BLACK: graphics::Color = graphics::Color::new(228.0/255.0, 196.0/255.0, 10
 const WHITE: graphics::Color = graphics::Color::new(188.0/255.0, 140.0/255.0, 76.0/255.0, 1.0);
 const SELECTED: graphics::Color = graphics::Color::new(0.0, 140.0/255.0, 10.0/255.0, 0.8);
 const HIGHLIGHTED: graphics::Color = graphics::Color::new(0.0, 140.0/255.0, 10.0/255.0, 0.3);
+const LAST_MOVE: graphics::Color = graphics::Color::new(200.0/255.0, 200.0/255.0, 0.0, 0.3);
+const CONSOLE_BACKGROUND: graphics::Color = graphics::Color::new(0.0, 0.0, 0.0, 0.75);
+const PROMOTION_BACKGROUND: graphics::Color = graphics::Color::new(0.0, 0.0, 0.0, 0.6);
 
-/// GUI logic and event implementation structure. 
+/// Promotion options offered in the picker overlay, nearest-to-farthest from the back rank.
+const PROMOTION_PIECES: [fn(Colour) -> Piece; 4] =
+    [Piece::Queen, Piece::Rook, Piece::Bishop, Piece::Knight];
+
+/// Number of backlog lines shown above the input line in the console overlay.
+const CONSOLE_BACKLOG_LINES: usize = 4;
+
+const TEXT_COLOR: graphics::Color = graphics::Color::new(1.0, 1.0, 1.0, 1.0);
+
+/// CP437-style monospace font atlas, one glyph per ASCII code point laid
+/// out in a 16x16 grid (as in ez-roguelike's tilesheet font).
+const FONT_ATLAS_PATH: &str = "/font.png";
+const FONT_COLUMNS: usize = 16;
+const FONT_ROWS: usize = 16;
+const FONT_GLYPH_SIZE: (f32, f32) = (8.0, 8.0);
+
+/// Piece atlas file, containing all twelve pieces laid out in a 6x2 grid
+/// (columns: king, queen, rook, pawn, bishop, knight; rows: black, white).
+const PIECE_ATLAS_PATH: &str = "/pieces.png";
+const ATLAS_COLUMNS: usize = 6;
+const ATLAS_ROWS: usize = 2;
+
+/// Resource-dir path the game is saved to and loaded from.
+const SAVE_PATH: &str = "/save.bin";
+
+/// On-disk representation of a game: just the move history, since
+/// `osveijer_chess::Game` itself doesn't derive `Serialize`. Restoring
+/// replays every move through `Game::make_move` from a fresh game.
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    moves: Vec<(String, String)>,
+}
+
+/// GUI logic and event implementation structure.
 struct AppState {
-    sprites: Vec<(Piece, graphics::Image)>,
+    piece_batch: SpriteBatch,
+    board_mesh: graphics::Mesh,
+    font_image: graphics::Image,
     game: Game,
     // Save piece positions, which tiles has been clicked, current colour, etc...
     selected_square: Option<(usize,usize)>,
-    highlighted_squares: Vec<(usize,usize)>
+    highlighted_squares: Vec<(usize,usize)>,
+    // Moves played so far, in `pos_string` form, so the game can be saved and replayed.
+    move_history: Vec<(String, String)>,
+    // When true, the board is drawn and clicked from black's side instead of white's.
+    flipped: bool,
+    // The two squares of the most recently completed move, tinted so the opponent can see the reply.
+    last_move: Option<((usize,usize),(usize,usize))>,
+    // Set when a pawn reaches the back rank; holds the pending (from, to) until a promotion piece is picked.
+    promoting: Option<((usize,usize),(usize,usize))>,
+    // Command console: `Some(buffer)` while open, with prior submissions kept in `console_backlog`.
+    console_input: Option<String>,
+    console_backlog: Vec<String>
 }
 
 impl AppState {
     /// Initialise new application, i.e. initialise new game and load resources.
     fn new(ctx: &mut Context) -> GameResult<AppState> {
 
-        
         let state = AppState {
-            sprites: AppState::load_sprites(ctx),
+            piece_batch: SpriteBatch::new(graphics::Image::new(ctx, PIECE_ATLAS_PATH)?),
+            board_mesh: AppState::build_board_mesh(ctx)?,
+            font_image: graphics::Image::new(ctx, FONT_ATLAS_PATH)?,
             game: Game::new(),
             selected_square: None,
-            highlighted_squares: vec![]
+            highlighted_squares: vec![],
+            move_history: vec![],
+            console_input: None,
+            console_backlog: vec![],
+            flipped: false,
+            last_move: None,
+            promoting: None
         };
 
         Ok(state)
     }
 
-    /// Loads chess piese images into vector.
-    fn load_sprites(ctx: &mut Context) -> Vec<(Piece, graphics::Image)> {
-
-        [
-            (Piece::King(Colour::Black), "/black_king.png".to_string()),
-            (Piece::Queen(Colour::Black), "/black_queen.png".to_string()),
-            (Piece::Rook(Colour::Black), "/black_rook.png".to_string()),
-            (Piece::Pawn(Colour::Black), "/black_pawn.png".to_string()),
-            (Piece::Bishop(Colour::Black), "/black_bishop.png".to_string()),
-            (Piece::Knight(Colour::Black), "/black_knight.png".to_string()),
-            (Piece::King(Colour::White), "/white_king.png".to_string()),
-            (Piece::Queen(Colour::White), "/white_queen.png".to_string()),
-            (Piece::Rook(Colour::White), "/white_rook.png".to_string()),
-            (Piece::Pawn(Colour::White), "/white_pawn.png".to_string()),
-            (Piece::Bishop(Colour::White), "/white_bishop.png".to_string()),
-            (Piece::Knight(Colour::White), "/white_knight.png".to_string())
-        ]
-        .iter()
-        .map(|(_piece, _path)| (*_piece, graphics::Image::new(ctx, _path).unwrap()))
-        .collect::<Vec<(Piece, graphics::Image)>>()
+    /// Initialise a new application with the game seeded from a FEN
+    /// position string instead of the usual starting position.
+    fn from_fen(ctx: &mut Context, fen: &str) -> GameResult<AppState> {
+        let mut state = AppState::new(ctx)?;
+        state.load_fen(fen).map_err(GameError::CustomError)?;
+        Ok(state)
+    }
+
+    /// Parses a FEN board placement (and active colour) directly into
+    /// `game.board`/`game.active`, since `osveijer_chess::Game` has no
+    /// FEN constructor of its own. Reachable from arbitrary user input via
+    /// the console's `fen` command, so malformed input is reported back
+    /// instead of panicking.
+    ///
+    /// Castling rights and the en passant target square aren't restored
+    /// (there's nowhere on `Game` to put them), so a FEN that claims either
+    /// is rejected rather than silently dropping them and letting the
+    /// engine get castling/en passant legality wrong.
+    fn load_fen(&mut self, fen: &str) -> Result<(), String> {
+        let mut parts = fen.split_whitespace();
+        let placement = parts.next().ok_or_else(|| "Empty FEN".to_string())?;
+
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != 8 {
+            return Err(format!("FEN must have 8 ranks, got {}", ranks.len()));
+        }
+
+        let mut board: [[Option<Piece>; 8]; 8] = [[None; 8]; 8];
+        for (_row, rank) in ranks.iter().enumerate() {
+            let mut _col = 0;
+            for ch in rank.chars() {
+                if _col >= 8 {
+                    return Err(format!("Rank '{}' does not fit 8 files", rank));
+                }
+
+                if let Some(skip) = ch.to_digit(10) {
+                    _col += skip as usize;
+                } else {
+                    let colour = if ch.is_uppercase() { Colour::White } else { Colour::Black };
+                    let piece = match ch.to_ascii_lowercase() {
+                        'k' => Piece::King(colour),
+                        'q' => Piece::Queen(colour),
+                        'r' => Piece::Rook(colour),
+                        'b' => Piece::Bishop(colour),
+                        'n' => Piece::Knight(colour),
+                        'p' => Piece::Pawn(colour),
+                        _ => return Err(format!("Unknown FEN piece '{}'", ch)),
+                    };
+                    board[_row][_col] = Some(piece);
+                    _col += 1;
+                }
+            }
+            if _col != 8 {
+                return Err(format!("Rank '{}' does not cover 8 files", rank));
+            }
+        }
+
+        let active = match parts.next() {
+            Some("b") => Colour::Black,
+            _ => Colour::White,
+        };
+
+        if let Some(castling) = parts.next() {
+            if castling != "-" {
+                return Err(format!(
+                    "FEN specifies castling rights ('{}') which this client can't restore",
+                    castling
+                ));
+            }
+        }
+        if let Some(en_passant) = parts.next() {
+            if en_passant != "-" {
+                return Err(format!(
+                    "FEN specifies an en passant target ('{}') which this client can't restore",
+                    en_passant
+                ));
+            }
+        }
+
+        self.game.board = board;
+        self.game.active = active;
+        self.move_history = Vec::new();
+        self.last_move = None;
+        self.promoting = None;
+        Ok(())
+    }
+
+    /// Writes the move history out to the resource dir so the game can be
+    /// restored later with `load`.
+    fn save(&self, ctx: &mut Context) -> GameResult {
+        let saved = SavedGame { moves: self.move_history.clone() };
+        let file = ggez::filesystem::create(ctx, SAVE_PATH)?;
+        bincode::serialize_into(file, &saved)
+            .map_err(|e| GameError::CustomError(format!("Failed to save game: {}", e)))
+    }
+
+    /// Reads a previously saved game back in and replays its moves onto a
+    /// fresh `Game` to reconstruct the position.
+    fn load(ctx: &mut Context) -> GameResult<AppState> {
+        let mut state = AppState::new(ctx)?;
+
+        let file = ggez::filesystem::open(ctx, SAVE_PATH)?;
+        let saved: SavedGame = bincode::deserialize_from(file)
+            .map_err(|e| GameError::CustomError(format!("Failed to load game: {}", e)))?;
+
+        for (from, to) in saved.moves {
+            state.game.make_move(from.clone(), to.clone());
+            state.last_move = Some((coord_from_pos_string(&from), coord_from_pos_string(&to)));
+            state.move_history.push((from, to));
+        }
+
+        Ok(state)
+    }
+
+    /// Whether `colour`'s king is currently attacked by any opposing piece.
+    /// Used to tell checkmate apart from stalemate, since the crate's
+    /// `GameState` only distinguishes `InProgress`/`Check`/`GameOver` and
+    /// doesn't say which ending a `GameOver` is.
+    fn is_in_check(&self, colour: Colour) -> bool {
+        let king_pos = (0..8)
+            .flat_map(|r| (0..8).map(move |c| (r, c)))
+            .find(|&(r, c)| self.game.board[r][c] == Some(Piece::King(colour)));
+
+        let king_pos = match king_pos {
+            Some(p) => p,
+            None => return false,
+        };
+
+        for r in 0..8 {
+            for c in 0..8 {
+                if get_colour(self.game.board[r][c]).map_or(false, |pc| pc != colour) {
+                    if let Some(moves) = self.game.get_possible_moves(pos_string((r, c))) {
+                        if moves.iter().any(|m| coord_from_pos_string(m) == king_pos) {
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Turn/check/checkmate/stalemate label shown in the status overlay.
+    fn status_label(&self) -> Option<&'static str> {
+        match self.game.get_game_state() {
+            GameState::Check => Some("Check"),
+            GameState::GameOver => {
+                if self.is_in_check(self.game.active) { Some("Checkmate") } else { Some("Stalemate") }
+            },
+            GameState::InProgress => None,
+        }
+    }
+
+    /// Whether moving the piece on `from` to `to` is a pawn reaching the back rank.
+    fn is_promotion_move(&self, from: (usize,usize), to: (usize,usize)) -> bool {
+        matches!(self.game.board[from.0][from.1], Some(Piece::Pawn(_))) && (to.0 == 0 || to.0 == 7)
+    }
+
+    /// The four board squares the promotion picker occupies, walking inward
+    /// from the back rank toward the centre of the board.
+    fn promotion_squares(to: (usize,usize)) -> [(usize,usize); 4] {
+        let step: isize = if to.0 == 0 { 1 } else { -1 };
+        let mut squares = [(0, 0); 4];
+        for (i, square) in squares.iter_mut().enumerate() {
+            *square = ((to.0 as isize + step * i as isize) as usize, to.1);
+        }
+        squares
+    }
+
+    /// Plays an ordinary move and records it as the last move.
+    fn commit_move(&mut self, from: (usize,usize), to: (usize,usize)) {
+        let (from_str, to_str) = (pos_string(from), pos_string(to));
+        self.game.make_move(from_str.clone(), to_str.clone());
+        self.move_history.push((from_str, to_str));
+        self.last_move = Some((from, to));
+    }
+
+    /// Plays a pawn promotion, selecting the chosen piece through the
+    /// engine's own promotion setter before committing the plain move
+    /// (the crate's `make_move` takes a plain 2-char destination; it has
+    /// no UCI-style promotion suffix).
+    fn commit_promotion(&mut self, from: (usize,usize), to: (usize,usize), piece: fn(Colour) -> Piece) {
+        let colour = get_colour(self.game.board[from.0][from.1]).unwrap_or(self.game.active);
+        self.game.set_promotion(piece(colour));
+
+        let (from_str, to_str) = (pos_string(from), pos_string(to));
+        self.game.make_move(from_str.clone(), to_str.clone());
+        self.move_history.push((from_str, to_str));
+        self.last_move = Some((from, to));
+        self.promoting = None;
+    }
+
+    /// Parses and runs one console command line, logging the result (or the
+    /// echoed command) to the backlog. Supported commands: a bare move like
+    /// `e2e4` (or `e7e8q` to promote), `fen <string>`, `moves <square>`, and
+    /// `reset`.
+    fn run_console_command(&mut self, line: &str) {
+        self.console_backlog.push(format!("> {}", line));
+
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("fen") => {
+                let fen = words.collect::<Vec<_>>().join(" ");
+                match self.load_fen(&fen) {
+                    Ok(()) => self.console_backlog.push(format!("Loaded FEN: {}", fen)),
+                    Err(e) => self.console_backlog.push(format!("Invalid FEN: {}", e)),
+                }
+            },
+            Some("moves") => {
+                match words.next() {
+                    // Hand the typed square straight to `get_possible_moves` instead of
+                    // round-tripping it through board coords: `pos_string` re-encodes rank
+                    // as `8 - row`, so parsing with a plain '1'->0 mapping and re-stringifying
+                    // would silently query the mirrored rank.
+                    Some(square) if square.len() == 2 => {
+                        match self.game.get_possible_moves(square.to_string()) {
+                            Some(moves) => self.console_backlog.push(format!("{:?}", moves)),
+                            None => self.console_backlog.push("No moves".to_string()),
+                        }
+                    },
+                    _ => self.console_backlog.push("Usage: moves <square>".to_string()),
+                }
+            },
+            Some("reset") => {
+                self.game = Game::new();
+                self.selected_square = None;
+                self.highlighted_squares = Vec::new();
+                self.move_history = Vec::new();
+                self.last_move = None;
+                self.promoting = None;
+                self.console_backlog.push("Game reset".to_string());
+            },
+            // Bare move, e.g. "e2e4" or, with a trailing promotion letter, "e7e8q".
+            // Indexed by char rather than byte so non-ASCII input can't panic on a
+            // char-boundary slice, and each square is checked to be a file a-h
+            // followed by a rank 1-8 before touching the engine.
+            Some(mv) if mv.chars().count() == 4 || mv.chars().count() == 5 => {
+                let chars: Vec<char> = mv.chars().collect();
+                let is_square = |file: char, rank: char| file.is_ascii_lowercase() && ('a'..='h').contains(&file)
+                    && rank.is_ascii_digit() && ('1'..='8').contains(&rank);
+
+                if !is_square(chars[0], chars[1]) || !is_square(chars[2], chars[3]) {
+                    self.console_backlog.push(format!("Invalid move: {}", mv));
+                    return;
+                }
+
+                let promotion: Option<fn(Colour) -> Piece> = match chars.get(4) {
+                    None => None,
+                    Some('q') => Some(Piece::Queen),
+                    Some('r') => Some(Piece::Rook),
+                    Some('b') => Some(Piece::Bishop),
+                    Some('n') => Some(Piece::Knight),
+                    Some(_) => {
+                        self.console_backlog.push(format!("Invalid move: {}", mv));
+                        return;
+                    },
+                };
+
+                let from = coord_from_pos_string(&chars[0..2].iter().collect::<String>());
+                let to = coord_from_pos_string(&chars[2..4].iter().collect::<String>());
+                match promotion {
+                    Some(piece) => self.commit_promotion(from, to, piece),
+                    None => self.commit_move(from, to),
+                }
+            },
+            Some(other) => self.console_backlog.push(format!("Unknown command: {}", other)),
+            None => {},
+        }
+    }
+
+    /// Maps an ASCII character to its glyph's source rectangle (normalised
+    /// 0.0-1.0) in the CP437-style font atlas.
+    fn glyph_rect(ch: char) -> graphics::Rect {
+        let code = ch as usize;
+        let col = code % FONT_COLUMNS;
+        let row = code / FONT_COLUMNS;
+        graphics::Rect::new(
+            col as f32 / FONT_COLUMNS as f32,
+            row as f32 / FONT_ROWS as f32,
+            1.0 / FONT_COLUMNS as f32,
+            1.0 / FONT_ROWS as f32,
+        )
+    }
+
+    /// Draws a line of text glyph-by-glyph from the font atlas.
+    fn draw_text(&self, ctx: &mut Context, text: &str, dest: [f32; 2], color: graphics::Color) {
+        for (i, ch) in text.chars().enumerate() {
+            graphics::draw(ctx, &self.font_image, graphics::DrawParam::default()
+                .src(AppState::glyph_rect(ch))
+                .color(color)
+                .dest([dest[0] + i as f32 * FONT_GLYPH_SIZE.0, dest[1]])
+            ).expect("Failed to draw text.");
+        }
+    }
+
+    /// Maps a piece variant to its source rectangle (normalised 0.0-1.0)
+    /// within the piece atlas in O(1) via a match, rather than scanning a
+    /// `Vec` or requiring `Piece` to implement `Eq`/`Hash` for a `HashMap`.
+    fn piece_rect(piece: Piece) -> graphics::Rect {
+        let cell_w = 1.0 / ATLAS_COLUMNS as f32;
+        let cell_h = 1.0 / ATLAS_ROWS as f32;
+
+        let (col, row) = match piece {
+            Piece::King(Colour::Black) => (0, 0),
+            Piece::Queen(Colour::Black) => (1, 0),
+            Piece::Rook(Colour::Black) => (2, 0),
+            Piece::Pawn(Colour::Black) => (3, 0),
+            Piece::Bishop(Colour::Black) => (4, 0),
+            Piece::Knight(Colour::Black) => (5, 0),
+            Piece::King(Colour::White) => (0, 1),
+            Piece::Queen(Colour::White) => (1, 1),
+            Piece::Rook(Colour::White) => (2, 1),
+            Piece::Pawn(Colour::White) => (3, 1),
+            Piece::Bishop(Colour::White) => (4, 1),
+            Piece::Knight(Colour::White) => (5, 1),
+        };
+
+        graphics::Rect::new(col as f32 * cell_w, row as f32 * cell_h, cell_w, cell_h)
+    }
+
+    /// Builds the checkered board as a single cached mesh, so it only has
+    /// to be assembled once instead of every frame in `draw`.
+    fn build_board_mesh(ctx: &mut Context) -> GameResult<graphics::Mesh> {
+        let mut builder = graphics::MeshBuilder::new();
+
+        for _row in 0..8 {
+            for _col in 0..8 {
+                builder.rectangle(
+                    graphics::DrawMode::fill(),
+                    graphics::Rect::new_i32(
+                        _col * GRID_CELL_SIZE.0 as i32,
+                        _row * GRID_CELL_SIZE.1 as i32,
+                        GRID_CELL_SIZE.0 as i32,
+                        GRID_CELL_SIZE.1 as i32,
+                    ),
+                    match _col % 2 {
+                        0 =>
+                            if _row % 2 == 0 { WHITE }
+                            else { BLACK },
+                        _ =>
+                            if _row % 2 == 0 { BLACK }
+                            else { WHITE },
+                    },
+                );
+            }
+        }
+
+        builder.build(ctx)
     }
 }
 
@@ -79,71 +466,155 @@ impl event::EventHandler<GameError> for AppState {
         // clear interface with gray background colour
         graphics::clear(ctx, [0.5, 0.5, 0.5, 1.0].into());
 
+        // draw the (cached) checkered board in a single call
+        graphics::draw(ctx, &self.board_mesh, graphics::DrawParam::default()).expect("Failed to draw board.");
 
-        // draw grid
-        for _row in 0..8 {
-            for _col in 0..8 {
-
-                // draw tile
-                let rectangle = graphics::Mesh::new_rectangle(ctx, 
-                    graphics::DrawMode::fill(), 
+        // tint the two squares of the last move so the opponent can see the reply
+        if let Some((from, to)) = self.last_move {
+            for square in [from, to] {
+                let screen = board_to_screen(self.flipped, square.0, square.1);
+                let rectangle = graphics::Mesh::new_rectangle(ctx,
+                    graphics::DrawMode::fill(),
                     graphics::Rect::new_i32(
-                        _col * GRID_CELL_SIZE.0 as i32,
-                        _row * GRID_CELL_SIZE.1 as i32,
+                        screen.1 as i32 * GRID_CELL_SIZE.0 as i32,
+                        screen.0 as i32 * GRID_CELL_SIZE.1 as i32,
                         GRID_CELL_SIZE.0 as i32,
                         GRID_CELL_SIZE.1 as i32,
-                    ), match _col % 2 {
-                        0 => 
-                            if _row % 2 == 0 { WHITE } 
-                            else { BLACK },
-                        _ => 
-                            if _row % 2 == 0 { BLACK } 
-                            else { WHITE },
-                    }).expect("Failed to create tile.");
+                    ),
+                    LAST_MOVE
+                    ).expect("Failed to create tile.");
                 graphics::draw(ctx, &rectangle, graphics::DrawParam::default()).expect("Failed to draw tiles.");
+            }
+        }
 
-                // draw piece
-                if self.game.board[_row as usize][_col as usize] != None {
-                    graphics::draw(ctx, &self.sprites.get(self.sprites.iter().position(|p| Some(p.0) == self.game.board[_row as usize][_col as usize]).unwrap()).unwrap().1, graphics::DrawParam::default()
+        // batch every occupied square's piece sprite and draw them all at once
+        self.piece_batch.clear();
+        for _row in 0..8 {
+            for _col in 0..8 {
+                let (rank, file) = screen_to_board(self.flipped, _row as usize, _col as usize);
+                if let Some(piece) = self.game.board[rank][file] {
+                    let rect = AppState::piece_rect(piece);
+                    self.piece_batch.add(graphics::DrawParam::default()
+                        .src(rect)
                         .scale([2.0, 2.0])  // Tile size is 90 pixels, while image sizes are 45 pixels.
                         .dest(
                             [_col as f32 * GRID_CELL_SIZE.0 as f32, _row as f32 * GRID_CELL_SIZE.1 as f32],
                         )
-                    ).expect("Failed to draw piece.");
+                    );
                 }
             }
         }
+        graphics::draw(ctx, &self.piece_batch, graphics::DrawParam::default()).expect("Failed to draw pieces.");
 
         if let Some(s) = self.selected_square {
             // draw selected square
-            let rectangle = graphics::Mesh::new_rectangle(ctx, 
-                graphics::DrawMode::fill(), 
+            let screen = board_to_screen(self.flipped, s.0, s.1);
+            let rectangle = graphics::Mesh::new_rectangle(ctx,
+                graphics::DrawMode::fill(),
                 graphics::Rect::new_i32(
-                    s.1 as i32 * GRID_CELL_SIZE.0 as i32,
-                    s.0 as i32 * GRID_CELL_SIZE.1 as i32,
+                    screen.1 as i32 * GRID_CELL_SIZE.0 as i32,
+                    screen.0 as i32 * GRID_CELL_SIZE.1 as i32,
                     GRID_CELL_SIZE.0 as i32,
                     GRID_CELL_SIZE.1 as i32,
-                ), 
+                ),
                 SELECTED
                 ).expect("Failed to create tile.");
             graphics::draw(ctx, &rectangle, graphics::DrawParam::default()).expect("Failed to draw tiles.");
 
             // draw highlighted squares
             for squ in self.highlighted_squares.iter() {
-                let rectangle = graphics::Mesh::new_rectangle(ctx, 
-                    graphics::DrawMode::fill(), 
+                let screen = board_to_screen(self.flipped, squ.0, squ.1);
+                let rectangle = graphics::Mesh::new_rectangle(ctx,
+                    graphics::DrawMode::fill(),
                     graphics::Rect::new_i32(
-                        squ.1 as i32 * GRID_CELL_SIZE.0 as i32,
-                        squ.0 as i32 * GRID_CELL_SIZE.1 as i32,
+                        screen.1 as i32 * GRID_CELL_SIZE.0 as i32,
+                        screen.0 as i32 * GRID_CELL_SIZE.1 as i32,
                         GRID_CELL_SIZE.0 as i32,
                         GRID_CELL_SIZE.1 as i32,
-                    ), 
+                    ),
                     HIGHLIGHTED
                     ).expect("Failed to create tile.");
                 graphics::draw(ctx, &rectangle, graphics::DrawParam::default()).expect("Failed to draw tiles.");
             }
         }
-        
+
+        // draw the promotion picker: queen/rook/bishop/knight, walking inward from the back rank
+        if let Some((from, to)) = self.promoting {
+            let colour = get_colour(self.game.board[from.0][from.1]).unwrap_or(self.game.active);
+            let squares = AppState::promotion_squares(to);
+
+            self.piece_batch.clear();
+            for (i, square) in squares.iter().enumerate() {
+                let screen = board_to_screen(self.flipped, square.0, square.1);
+                let dest = [screen.1 as f32 * GRID_CELL_SIZE.0 as f32, screen.0 as f32 * GRID_CELL_SIZE.1 as f32];
+
+                let background = graphics::Mesh::new_rectangle(ctx,
+                    graphics::DrawMode::fill(),
+                    graphics::Rect::new(dest[0], dest[1], GRID_CELL_SIZE.0 as f32, GRID_CELL_SIZE.1 as f32),
+                    PROMOTION_BACKGROUND
+                    ).expect("Failed to create tile.");
+                graphics::draw(ctx, &background, graphics::DrawParam::default()).expect("Failed to draw tiles.");
+
+                let rect = AppState::piece_rect(PROMOTION_PIECES[i](colour));
+                self.piece_batch.add(graphics::DrawParam::default()
+                    .src(rect)
+                    .scale([2.0, 2.0])
+                    .dest(dest)
+                );
+            }
+            graphics::draw(ctx, &self.piece_batch, graphics::DrawParam::default()).expect("Failed to draw promotion picker.");
+        }
+
+        // label files a-h along the bottom edge, reusing pos_string's mapping
+        for _col in 0..8 {
+            let (_, file) = screen_to_board(self.flipped, 0, _col);
+            let file_char = (b'a' + file as u8) as char;
+            self.draw_text(ctx, &file_char.to_string(), [
+                _col as f32 * GRID_CELL_SIZE.0 as f32 + 4.0,
+                SCREEN_SIZE.1 - FONT_GLYPH_SIZE.1 - 4.0,
+            ], TEXT_COLOR);
+        }
+
+        // label ranks 1-8 along the left edge, reusing pos_string's mapping
+        for _row in 0..8 {
+            let (rank, _) = screen_to_board(self.flipped, _row, 0);
+            let rank_char = char::from_digit(8 - rank as u32, 10).unwrap();
+            self.draw_text(ctx, &rank_char.to_string(), [4.0, _row as f32 * GRID_CELL_SIZE.1 as f32 + 4.0], TEXT_COLOR);
+        }
+
+        // show whose turn it is, and check/checkmate/stalemate status
+        let turn_label = match self.game.active {
+            Colour::White => "White to move",
+            Colour::Black => "Black to move",
+        };
+        self.draw_text(ctx, turn_label, [4.0, 4.0], TEXT_COLOR);
+
+        if let Some(label) = self.status_label() {
+            self.draw_text(ctx, label, [4.0, 4.0 + FONT_GLYPH_SIZE.1 + 2.0], TEXT_COLOR);
+        }
+
+        // draw the command console, if open, over the bottom two rows
+        if let Some(input) = &self.console_input {
+            let console_height = GRID_CELL_SIZE.1 as f32 * 2.0;
+            let console_top = SCREEN_SIZE.1 - console_height;
+
+            let background = graphics::Mesh::new_rectangle(ctx,
+                graphics::DrawMode::fill(),
+                graphics::Rect::new(0.0, console_top, SCREEN_SIZE.0, console_height),
+                CONSOLE_BACKGROUND
+                ).expect("Failed to create console background.");
+            graphics::draw(ctx, &background, graphics::DrawParam::default()).expect("Failed to draw console background.");
+
+            let backlog_start = self.console_backlog.len().saturating_sub(CONSOLE_BACKLOG_LINES);
+            let mut lines: Vec<&str> = self.console_backlog[backlog_start..].iter().map(String::as_str).collect();
+            let input_line = format!("> {}_", input);
+            lines.push(&input_line);
+
+            for (i, line) in lines.iter().enumerate() {
+                self.draw_text(ctx, line, [4.0, console_top + 4.0 + i as f32 * (FONT_GLYPH_SIZE.1 + 2.0)], TEXT_COLOR);
+            }
+        }
+
         // render updated graphics
         graphics::present(ctx).expect("Failed to update graphics.");
 
@@ -154,15 +625,29 @@ impl event::EventHandler<GameError> for AppState {
     fn mouse_button_up_event(&mut self, ctx: &mut Context, button: event::MouseButton, x: f32, y: f32) {
         if button == event::MouseButton::Left {
             /* check click position and update board accordingly */
-            let rank = (y / GRID_CELL_SIZE.1 as f32).floor() as usize;
-            let file = (x / GRID_CELL_SIZE.0 as f32).floor() as usize;
+            let screen_row = (y / GRID_CELL_SIZE.1 as f32).floor() as usize;
+            let screen_col = (x / GRID_CELL_SIZE.0 as f32).floor() as usize;
+            let (rank, file) = screen_to_board(self.flipped, screen_row, screen_col);
+
+            if let Some((from, to)) = self.promoting {
+                match AppState::promotion_squares(to).iter().position(|&sq| sq == (rank, file)) {
+                    Some(i) => self.commit_promotion(from, to, PROMOTION_PIECES[i]),
+                    None => self.promoting = None,
+                }
+                return;
+            }
+
             match self.selected_square {
                 Some(pos) => {
                     if pos == (rank, file) {
                         self.selected_square = None;
                         self.highlighted_squares = Vec::new();
                     } else if self.highlighted_squares.iter().any(|p| p == &(rank,file)) {
-                        self.game.make_move(pos_string(pos), pos_string((rank,file)));
+                        if self.is_promotion_move(pos, (rank, file)) {
+                            self.promoting = Some((pos, (rank, file)));
+                        } else {
+                            self.commit_move(pos, (rank, file));
+                        }
                         self.selected_square = None;
                         self.highlighted_squares = Vec::new();
                     } else {
@@ -170,7 +655,8 @@ impl event::EventHandler<GameError> for AppState {
                         self.highlighted_squares = Vec::new();
                         if let Some(c) = get_colour(self.game.board[rank][file]) {
                             if c == self.game.active {
-                                self.highlighted_squares = pos_coord_vec(self.game.get_possible_moves(pos_string((rank,file))).unwrap());
+                                self.highlighted_squares = self.game.get_possible_moves(pos_string((rank,file))).unwrap()
+                                    .iter().map(|m| coord_from_pos_string(m)).collect();
                             };
                         };
                     }
@@ -180,7 +666,8 @@ impl event::EventHandler<GameError> for AppState {
                     self.highlighted_squares = Vec::new();
                     if let Some(c) = get_colour(self.game.board[rank][file]) {
                         if c == self.game.active {
-                            self.highlighted_squares = pos_coord_vec(self.game.get_possible_moves(pos_string((rank,file))).unwrap());
+                            self.highlighted_squares = self.game.get_possible_moves(pos_string((rank,file))).unwrap()
+                                .iter().map(|m| coord_from_pos_string(m)).collect();
                         };
                     };
                 }
@@ -195,12 +682,57 @@ impl event::EventHandler<GameError> for AppState {
         _keymods: event::KeyMods,
         _repeat: bool,
     ) {
+        if keycode == event::KeyCode::Grave {
+            self.console_input = match self.console_input {
+                Some(_) => None,
+                None => Some(String::new()),
+            };
+            return;
+        }
+
+        if let Some(input) = &mut self.console_input {
+            match keycode {
+                event::KeyCode::Return => {
+                    let line = input.clone();
+                    self.console_input = Some(String::new());
+                    self.run_console_command(&line);
+                },
+                event::KeyCode::Back => { input.pop(); },
+                event::KeyCode::Escape => { self.console_input = None; },
+                _ => {},
+            }
+            return;
+        }
+
         if keycode == event::KeyCode::Escape {
             event::quit(ctx);
         } else if keycode == event::KeyCode::R {
             self.game = Game::new();
             self.selected_square = None;
             self.highlighted_squares = Vec::new();
+            self.move_history = Vec::new();
+            self.last_move = None;
+            self.promoting = None;
+        } else if keycode == event::KeyCode::S {
+            if let Err(e) = self.save(ctx) {
+                eprintln!("Failed to save game: {}", e);
+            }
+        } else if keycode == event::KeyCode::L {
+            match AppState::load(ctx) {
+                Ok(state) => *self = state,
+                Err(e) => eprintln!("Failed to load game: {}", e),
+            }
+        } else if keycode == event::KeyCode::F {
+            self.flipped = !self.flipped;
+        }
+    }
+
+    /// Accumulates typed characters into the console input buffer while it's open.
+    fn text_input_event(&mut self, _ctx: &mut Context, character: char) {
+        if let Some(input) = &mut self.console_input {
+            if character != '`' && !character.is_control() {
+                input.push(character);
+            }
         }
     }
 }
@@ -223,7 +755,19 @@ pub fn main() -> GameResult {
         );
     let (mut contex, mut event_loop) = context_builder.build().expect("Failed to build context.");
 
-    let state = AppState::new(&mut contex).expect("Failed to create state.");
+    // An optional FEN string passed as the first CLI argument seeds the
+    // starting position instead of the usual new game. A malformed FEN
+    // falls back to a fresh game rather than aborting startup.
+    let state = match env::args().nth(1) {
+        Some(fen) => match AppState::from_fen(&mut contex, &fen) {
+            Ok(state) => state,
+            Err(e) => {
+                eprintln!("Ignoring invalid FEN ({}), starting a new game instead.", e);
+                AppState::new(&mut contex).expect("Failed to create state.")
+            },
+        },
+        None => AppState::new(&mut contex).expect("Failed to create state."),
+    };
     event::run(contex, event_loop, state)       // Run window event loop
 }
 
@@ -234,6 +778,18 @@ fn get_colour(piece: Option<Piece>) -> Option<Colour> {
     }
 }
 
+/// Converts a screen-space (row, col) into board-space (rank, file),
+/// inverting both axes when the board is viewed from black's side.
+fn screen_to_board(flipped: bool, row: usize, col: usize) -> (usize, usize) {
+    if flipped { (7 - row, 7 - col) } else { (row, col) }
+}
+
+/// Converts a board-space (rank, file) into screen-space (row, col). The
+/// flip inverts both axes, so it's its own inverse: identical to `screen_to_board`.
+fn board_to_screen(flipped: bool, rank: usize, file: usize) -> (usize, usize) {
+    screen_to_board(flipped, rank, file)
+}
+
 fn pos_string(_pos: (usize, usize)) -> String  {
     let mut string1 = String::new();
 
@@ -256,34 +812,22 @@ fn pos_string(_pos: (usize, usize)) -> String  {
     string1
 }
 
-fn pos_coord_vec(vec: Vec<String>) -> Vec<(usize,usize)> {
-    let mut out = Vec::new();
-    for i in vec {
-        let chars: Vec<char> = i.chars().collect();
-        out.push((
-            match chars[1] {
-                '1' => 0,
-                '2' => 1,
-                '3' => 2,
-                '4' => 3,
-                '5' => 4,
-                '6' => 5,
-                '7' => 6,
-                '8' => 7,
-                _ => panic!("Rank wrong")
-            },
-            match chars[0] {
-                'a' => 0,
-                'b' => 1,
-                'c' => 2,
-                'd' => 3,
-                'e' => 4,
-                'f' => 5,
-                'g' => 6,
-                'h' => 7,
-                _ => panic!("File wrong")
-            }
-        ));
-    }
-    out
-}
\ No newline at end of file
+/// Inverse of `pos_string`: turns an algebraic square like "e4" back into
+/// board coords, using the same `8 - row` rank encoding so it round-trips
+/// correctly through `pos_string`. This is the only string->coord decoder
+/// in the file; route every square-string conversion through it.
+fn coord_from_pos_string(square: &str) -> (usize, usize) {
+    let chars: Vec<char> = square.chars().collect();
+    let file = match chars[0] {
+        'a' => 0, 'b' => 1, 'c' => 2, 'd' => 3,
+        'e' => 4, 'f' => 5, 'g' => 6, 'h' => 7,
+        _ => panic!("File wrong")
+    };
+    let rank = match chars[1] {
+        '8' => 0, '7' => 1, '6' => 2, '5' => 3,
+        '4' => 4, '3' => 5, '2' => 6, '1' => 7,
+        _ => panic!("Rank wrong")
+    };
+    (rank, file)
+}
+